@@ -3,11 +3,120 @@
 //! This module provides a thread-safe system monitor that polls CPU and memory
 //! usage in a background thread, keeping the UI thread free.
 
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::{Arc, OnceLock};
-use std::thread;
-use std::time::Duration;
-use sysinfo::System;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use sysinfo::{Disks, Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+/// Number of samples retained per metric (one sample per tick, ~5 minutes at 1s ticks).
+const HISTORY_CAPACITY: usize = 300;
+
+/// Fixed-capacity circular buffer of `f32` samples, oldest-to-newest on read.
+struct RingBuffer {
+    samples: [f32; HISTORY_CAPACITY],
+    /// Index where the next sample will be written.
+    head: usize,
+    /// Number of valid samples written so far (saturates at `HISTORY_CAPACITY`).
+    len: usize,
+}
+
+impl RingBuffer {
+    fn new() -> Self {
+        Self {
+            samples: [0.0; HISTORY_CAPACITY],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: f32) {
+        self.samples[self.head] = value;
+        self.head = (self.head + 1) % HISTORY_CAPACITY;
+        if self.len < HISTORY_CAPACITY {
+            self.len += 1;
+        }
+    }
+
+    /// Returns a snapshot of the retained samples, oldest-to-newest.
+    fn snapshot(&self) -> Vec<f32> {
+        if self.len < HISTORY_CAPACITY {
+            self.samples[..self.len].to_vec()
+        } else {
+            let mut out = Vec::with_capacity(HISTORY_CAPACITY);
+            out.extend_from_slice(&self.samples[self.head..]);
+            out.extend_from_slice(&self.samples[..self.head]);
+            out
+        }
+    }
+
+    /// Windowed min/max/mean over the retained samples. Returns `None` if empty.
+    fn aggregates(&self) -> Option<(f32, f32, f32)> {
+        if self.len == 0 {
+            return None;
+        }
+        let snapshot = self.snapshot();
+        let min = snapshot.iter().copied().fold(f32::MAX, f32::min);
+        let max = snapshot.iter().copied().fold(f32::MIN, f32::max);
+        let mean = snapshot.iter().sum::<f32>() / snapshot.len() as f32;
+        Some((min, max, mean))
+    }
+}
+
+/// Number of raw samples kept in the slot-window smoothing mode.
+const SMOOTHING_SLOTS: usize = 8;
+
+/// How raw CPU samples are damped before being reported via [`get_cpu_usage_smoothed`].
+#[derive(Clone, Copy)]
+pub enum CpuSmoothing {
+    /// Average of the last [`SMOOTHING_SLOTS`] raw samples, as used by TiKV's
+    /// `ThreadLoadStatistics` to make the reported curve smooth.
+    SlotWindow,
+    /// `smoothed = alpha * raw + (1 - alpha) * smoothed`.
+    Ewma { alpha: f32 },
+}
+
+/// Mutable state backing [`CpuSmoothing`]; lives behind a `Mutex` since it's updated once per
+/// tick from the background thread and read rarely, so contention is not a concern.
+struct SmoothingState {
+    mode: CpuSmoothing,
+    slots: [f32; SMOOTHING_SLOTS],
+    slot_idx: usize,
+    slot_len: usize,
+    ewma_value: f32,
+}
+
+impl SmoothingState {
+    fn new(mode: CpuSmoothing) -> Self {
+        Self {
+            mode,
+            slots: [0.0; SMOOTHING_SLOTS],
+            slot_idx: 0,
+            slot_len: 0,
+            ewma_value: 0.0,
+        }
+    }
+
+    /// Record a new raw sample and return the updated smoothed value.
+    fn push(&mut self, raw: f32) -> f32 {
+        match self.mode {
+            CpuSmoothing::SlotWindow => {
+                self.slots[self.slot_idx] = raw;
+                self.slot_idx = (self.slot_idx + 1) % SMOOTHING_SLOTS;
+                if self.slot_len < SMOOTHING_SLOTS {
+                    self.slot_len += 1;
+                }
+                self.slots[..self.slot_len].iter().sum::<f32>() / self.slot_len as f32
+            }
+            CpuSmoothing::Ewma { alpha } => {
+                self.ewma_value = alpha * raw + (1.0 - alpha) * self.ewma_value;
+                self.ewma_value
+            }
+        }
+    }
+}
 
 /// Shared system stats, updated by background thread
 struct SystemStats {
@@ -15,74 +124,612 @@ struct SystemStats {
     cpu_usage: AtomicU32,
     /// Memory usage scaled to 0-10000 (representing 0.00% to 100.00%)
     memory_usage: AtomicU32,
+    /// Rolling history of raw CPU usage samples (0.0-1.0), one push per tick
+    cpu_history: Mutex<RingBuffer>,
+    /// Rolling history of raw memory usage samples (0.0-1.0), one push per tick
+    memory_history: Mutex<RingBuffer>,
+    /// This process's CPU usage scaled to 0-10000, already normalized by core count
+    /// (see [`get_process_cpu_usage`] for the normalization convention)
+    process_cpu_usage: AtomicU32,
+    /// This process's resident set size, in bytes
+    process_memory_bytes: AtomicU64,
+    /// Smoothed CPU usage scaled to 0-10000, damped per [`CpuSmoothing`]
+    cpu_usage_smoothed: AtomicU32,
+    /// Smoothing mode and its running state
+    cpu_smoothing: Mutex<SmoothingState>,
+    /// Disk usage of the main data volume, scaled to 0-10000 (0.00% to 100.00% used)
+    disk_usage: AtomicU32,
+    /// Slow-changing CPU context (core count, frequency, load average, thread count)
+    cpu_info: Mutex<CpuInfo>,
+    /// Registered threshold-crossing subscriptions, checked after each sample
+    thresholds: Mutex<Vec<ThresholdSubscription>>,
 }
 
 impl SystemStats {
-    fn new() -> Self {
+    fn new(smoothing: CpuSmoothing) -> Self {
         Self {
             cpu_usage: AtomicU32::new(0),
             memory_usage: AtomicU32::new(0),
+            cpu_history: Mutex::new(RingBuffer::new()),
+            memory_history: Mutex::new(RingBuffer::new()),
+            process_cpu_usage: AtomicU32::new(0),
+            process_memory_bytes: AtomicU64::new(0),
+            cpu_usage_smoothed: AtomicU32::new(0),
+            cpu_smoothing: Mutex::new(SmoothingState::new(smoothing)),
+            disk_usage: AtomicU32::new(0),
+            cpu_info: Mutex::new(CpuInfo::default()),
+            thresholds: Mutex::new(Vec::new()),
         }
     }
 }
 
-/// Global system monitor instance
-static SYSTEM_MONITOR: OnceLock<Arc<SystemStats>> = OnceLock::new();
+/// A metric that can be watched with [`register_threshold`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Metric {
+    Cpu,
+    Memory,
+    Disk,
+}
+
+/// Which side of the threshold level counts as "alerting".
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Above,
+    Below,
+}
+
+/// Emitted once when a watched metric crosses a registered threshold.
+#[derive(Clone, Copy, Debug)]
+pub struct ThresholdEvent {
+    pub metric: Metric,
+    pub level: f64,
+    pub direction: Direction,
+    pub value: f64,
+}
 
-/// Start the background system monitor thread if not already running.
-/// This should be called once at app startup.
+/// A single `register_threshold` registration, tracked so crossings can be edge-triggered
+/// (fire once on crossing into the alerting zone, not every tick while past it).
+struct ThresholdSubscription {
+    metric: Metric,
+    level: f64,
+    direction: Direction,
+    /// Whether the most recent sample was on the alerting side of `level`.
+    armed: bool,
+    callback: Box<dyn Fn(ThresholdEvent) + Send + Sync>,
+}
+
+/// Compare `value` for `metric` against all matching registered thresholds and fire the
+/// callback for any that just crossed into their alerting zone. Called while holding the
+/// thresholds lock, so callbacks should stay cheap (e.g. send to a channel). This runs on the
+/// monitor's own background thread, so a panicking callback is caught with `catch_unwind` --
+/// without that, it would poison the thresholds mutex and silently kill the monitor thread,
+/// taking every other metric (CPU/memory/disk) down with it, not just this subscription.
+fn check_thresholds(stats: &SystemStats, metric: Metric, value: f64) {
+    let mut thresholds = stats.thresholds.lock().unwrap();
+    for sub in thresholds.iter_mut().filter(|sub| sub.metric == metric) {
+        let crossed = match sub.direction {
+            Direction::Above => value > sub.level,
+            Direction::Below => value < sub.level,
+        };
+        if crossed && !sub.armed {
+            sub.armed = true;
+            let event = ThresholdEvent {
+                metric: sub.metric,
+                level: sub.level,
+                direction: sub.direction,
+                value,
+            };
+            let callback = &sub.callback;
+            if std::panic::catch_unwind(AssertUnwindSafe(|| callback(event))).is_err() {
+                eprintln!(
+                    "system_monitor: threshold callback for {:?} panicked; subscription kept, \
+                     but check your callback -- a panic here would otherwise take down the \
+                     whole monitor thread",
+                    metric
+                );
+            }
+        } else if !crossed {
+            sub.armed = false;
+        }
+    }
+}
+
+/// Number of CPU ticks between refreshes of [`CpuInfo`]. Core count, frequency and load average
+/// all change slowly (if ever), so there's no need to pay for them on every tick.
+const CPU_INFO_SUB_TICKS: u32 = 8;
+
+/// Richer CPU context beyond a single usage percentage: core count, clock speed, the classic
+/// Unix 1/5/15-minute load averages, and the current thread count.
+#[derive(Clone, Default)]
+pub struct CpuInfo {
+    /// Number of logical CPUs.
+    pub cpu_num: usize,
+    /// CPU frequency of the first core, in MHz.
+    pub cpu_freq_mhz: u64,
+    /// 1-minute load average. Unavailable on Windows, where `sysinfo` reports 0.0.
+    pub load_avg_one: f64,
+    /// 5-minute load average. Unavailable on Windows, where `sysinfo` reports 0.0.
+    pub load_avg_five: f64,
+    /// 15-minute load average. Unavailable on Windows, where `sysinfo` reports 0.0.
+    pub load_avg_fifteen: f64,
+    /// Total number of threads across all running processes. `sysinfo::Process::tasks()` only
+    /// enumerates threads on Linux; on every other platform (macOS, Windows) it returns `None`
+    /// and each process falls back to counting as a single thread here, so on those platforms
+    /// this is really a process count, not a thread count.
+    pub num_threads: usize,
+}
+
+/// Pick the disk to report as the "main" data volume: the one mounted at `/` on Unix, or
+/// otherwise the disk with the most total space (typically the primary volume).
+fn main_disk(disks: &Disks) -> Option<&sysinfo::Disk> {
+    disks
+        .list()
+        .iter()
+        .find(|disk| disk.mount_point().as_os_str() == "/")
+        .or_else(|| disks.list().iter().max_by_key(|disk| disk.total_space()))
+}
+
+/// Tunable parameters for [`start_system_monitor_with`].
+pub struct SystemMonitorConfig {
+    /// How often to refresh and sample CPU usage.
+    pub cpu_interval: Duration,
+    /// How often to refresh memory usage. Memory refreshes are cheaper than CPU refreshes, so
+    /// this can be left at the default even when `cpu_interval` is tightened.
+    pub memory_interval: Duration,
+    /// How raw CPU samples are damped before being exposed via [`get_cpu_usage_smoothed`].
+    pub smoothing: CpuSmoothing,
+    /// Whether to additionally track this process's own CPU/memory footprint (see
+    /// [`get_process_cpu_usage`] / [`get_process_memory_bytes`]).
+    pub track_process: bool,
+    /// How often to refresh disk usage.
+    pub disk_interval: Duration,
+    /// Whether to track disk usage (see [`get_disk_usage`]). `sysinfo::Disk` has no
+    /// cross-platform cumulative read/write byte counters, so unlike CPU/memory/process this
+    /// only covers disk *space*, not I/O throughput.
+    pub track_disk: bool,
+}
+
+impl Default for SystemMonitorConfig {
+    fn default() -> Self {
+        Self {
+            cpu_interval: Duration::from_secs(1),
+            memory_interval: Duration::from_secs(1),
+            smoothing: CpuSmoothing::SlotWindow,
+            track_process: true,
+            disk_interval: Duration::from_secs(1),
+            track_disk: true,
+        }
+    }
+}
+
+/// Background thread handle plus the shared stats it publishes into.
+struct Monitor {
+    stats: Arc<SystemStats>,
+    /// Set by [`stop_system_monitor`]; checked once per loop iteration.
+    exit: Arc<AtomicBool>,
+    /// Taken and joined by [`stop_system_monitor`] so shutdown is deterministic.
+    join_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+/// Global system monitor instance. A `Mutex<Option<_>>` rather than a `OnceLock` so that
+/// [`stop_system_monitor`] can clear it and a later [`start_system_monitor`] actually spins a
+/// fresh thread back up, instead of silently no-op'ing forever.
+static SYSTEM_MONITOR: Mutex<Option<Arc<Monitor>>> = Mutex::new(None);
+
+/// Clone of the current monitor handle, if the background thread is running.
+fn monitor() -> Option<Arc<Monitor>> {
+    SYSTEM_MONITOR.lock().unwrap().clone()
+}
+
+/// Start the background system monitor thread with the default configuration, if not already
+/// running. This should be called once at app startup; safe to call again after
+/// [`stop_system_monitor`] to restart it.
 pub fn start_system_monitor() {
-    SYSTEM_MONITOR.get_or_init(|| {
-        let stats = Arc::new(SystemStats::new());
+    start_system_monitor_with(SystemMonitorConfig::default());
+}
+
+/// Start the background system monitor thread with a custom [`SystemMonitorConfig`], if not
+/// already running. This should be called once at app startup; safe to call again after
+/// [`stop_system_monitor`] to restart it.
+pub fn start_system_monitor_with(config: SystemMonitorConfig) {
+    let mut slot = SYSTEM_MONITOR.lock().unwrap();
+    if slot.is_some() {
+        return;
+    }
+
+    let monitor = {
+        let stats = Arc::new(SystemStats::new(config.smoothing));
         let stats_clone = Arc::clone(&stats);
+        let exit = Arc::new(AtomicBool::new(false));
+        let exit_clone = Arc::clone(&exit);
+        let cpu_interval = config.cpu_interval;
+        let memory_interval = config.memory_interval;
+        let track_process = config.track_process;
+        let disk_interval = config.disk_interval;
+        let track_disk = config.track_disk;
+        // The loop wakes up at the finer-grained interval and refreshes each metric only once
+        // its own interval has elapsed, so CPU and memory can be sampled at different rates.
+        let tick = cpu_interval.min(memory_interval).min(disk_interval);
 
-        thread::Builder::new()
+        let join_handle = thread::Builder::new()
             .name("system-monitor".to_string())
             .spawn(move || {
                 let mut sys = System::new_all();
+                let pid = Pid::from_u32(std::process::id());
+                let num_cores = sys.cpus().len().max(1) as f32;
+                let mut last_cpu_refresh = Instant::now() - cpu_interval;
+                let mut last_memory_refresh = Instant::now() - memory_interval;
+                let mut last_disk_refresh = Instant::now() - disk_interval;
+                let mut disks = Disks::new_with_refreshed_list();
+                let mut cpu_info_tick: u32 = 0;
+
+                while !exit_clone.load(Ordering::Relaxed) {
+                    let now = Instant::now();
 
-                loop {
-                    // Refresh CPU and memory
-                    sys.refresh_cpu_usage();
-                    sys.refresh_memory();
-
-                    // Get CPU usage (0.0 - 100.0)
-                    let cpu = sys.global_cpu_usage();
-                    let cpu_scaled = (cpu * 100.0) as u32; // Scale to 0-10000
-                    stats_clone.cpu_usage.store(cpu_scaled, Ordering::Relaxed);
-
-                    // Get memory usage
-                    let total_memory = sys.total_memory();
-                    let used_memory = sys.used_memory();
-                    let memory_pct = if total_memory > 0 {
-                        (used_memory as f64 / total_memory as f64 * 10000.0) as u32
-                    } else {
-                        0
-                    };
-                    stats_clone.memory_usage.store(memory_pct, Ordering::Relaxed);
-
-                    // Sleep for 1 second
-                    thread::sleep(Duration::from_secs(1));
+                    if now.duration_since(last_cpu_refresh) >= cpu_interval {
+                        last_cpu_refresh = now;
+                        sys.refresh_cpu_usage();
+                        if track_process {
+                            sys.refresh_processes_specifics(
+                                ProcessesToUpdate::Some(&[pid]),
+                                true,
+                                ProcessRefreshKind::nothing().with_cpu(),
+                            );
+                        }
+
+                        // Get CPU usage (0.0 - 100.0)
+                        let cpu = sys.global_cpu_usage();
+                        let cpu_scaled = (cpu * 100.0) as u32; // Scale to 0-10000
+                        stats_clone.cpu_usage.store(cpu_scaled, Ordering::Relaxed);
+                        stats_clone
+                            .cpu_history
+                            .lock()
+                            .unwrap()
+                            .push(cpu / 100.0);
+
+                        // Damp the raw sample so the UI readout doesn't flicker.
+                        let smoothed =
+                            stats_clone.cpu_smoothing.lock().unwrap().push(cpu / 100.0);
+                        stats_clone
+                            .cpu_usage_smoothed
+                            .store((smoothed * 10000.0) as u32, Ordering::Relaxed);
+
+                        // Check against the smoothed value, not the raw sample: thresholds
+                        // exist to flag sustained pressure, and raw per-tick jitter around a
+                        // registered level would otherwise re-arm and re-fire on every wobble.
+                        check_thresholds(&stats_clone, Metric::Cpu, smoothed as f64);
+
+                        // Get this process's own CPU footprint. `sysinfo` reports process CPU
+                        // relative to a single core (so it can exceed 100% on a multicore
+                        // machine) -- normalize by the core count so the value is comparable
+                        // to the global CPU percentage above.
+                        if track_process {
+                            if let Some(process) = sys.process(pid) {
+                                let process_cpu = process.cpu_usage() / num_cores;
+                                let process_cpu_scaled = (process_cpu * 100.0) as u32;
+                                stats_clone
+                                    .process_cpu_usage
+                                    .store(process_cpu_scaled, Ordering::Relaxed);
+                            }
+                        }
+
+                        // Core count, frequency and load average change slowly, so only
+                        // refresh this richer CPU context every `CPU_INFO_SUB_TICKS` ticks.
+                        if cpu_info_tick % CPU_INFO_SUB_TICKS == 0 {
+                            // `everything()` (rather than `nothing()`) is required here: thread
+                            // enumeration isn't opted into by any individual `with_*` flag, so
+                            // without it `process.tasks()` below is always `None` and the
+                            // per-process fallback silently reports a process count instead of a
+                            // thread count. This refresh is only done every
+                            // `CPU_INFO_SUB_TICKS` ticks, so the extra cost is acceptable.
+                            sys.refresh_processes_specifics(
+                                ProcessesToUpdate::All,
+                                true,
+                                ProcessRefreshKind::everything(),
+                            );
+                            let load_avg = System::load_average();
+                            let num_threads = sys
+                                .processes()
+                                .values()
+                                .map(|process| process.tasks().map_or(1, |tasks| tasks.len()))
+                                .sum();
+                            *stats_clone.cpu_info.lock().unwrap() = CpuInfo {
+                                cpu_num: sys.cpus().len(),
+                                cpu_freq_mhz: sys.cpus().first().map_or(0, |cpu| cpu.frequency()),
+                                load_avg_one: load_avg.one,
+                                load_avg_five: load_avg.five,
+                                load_avg_fifteen: load_avg.fifteen,
+                                num_threads,
+                            };
+                        }
+                        cpu_info_tick = cpu_info_tick.wrapping_add(1);
+                    }
+
+                    if now.duration_since(last_memory_refresh) >= memory_interval {
+                        last_memory_refresh = now;
+                        sys.refresh_memory();
+                        if track_process {
+                            sys.refresh_processes_specifics(
+                                ProcessesToUpdate::Some(&[pid]),
+                                true,
+                                ProcessRefreshKind::nothing().with_memory(),
+                            );
+                        }
+
+                        // Get memory usage
+                        let total_memory = sys.total_memory();
+                        let used_memory = sys.used_memory();
+                        let memory_pct = if total_memory > 0 {
+                            (used_memory as f64 / total_memory as f64 * 10000.0) as u32
+                        } else {
+                            0
+                        };
+                        stats_clone.memory_usage.store(memory_pct, Ordering::Relaxed);
+                        check_thresholds(&stats_clone, Metric::Memory, memory_pct as f64 / 10000.0);
+                        stats_clone
+                            .memory_history
+                            .lock()
+                            .unwrap()
+                            .push((memory_pct as f32) / 10000.0);
+
+                        if track_process {
+                            if let Some(process) = sys.process(pid) {
+                                stats_clone
+                                    .process_memory_bytes
+                                    .store(process.memory(), Ordering::Relaxed);
+                            }
+                        }
+                    }
+
+                    // `sysinfo::Disk` exposes total/available space but no cumulative
+                    // read/write byte counters (those only exist per-process, via
+                    // `Process::disk_usage()`), so this tracks disk *space* only -- disk I/O
+                    // throughput would need an OS-specific source (e.g. `/proc/diskstats`).
+                    if track_disk && now.duration_since(last_disk_refresh) >= disk_interval {
+                        last_disk_refresh = now;
+                        disks.refresh(true);
+
+                        if let Some(disk) = main_disk(&disks) {
+                            let total = disk.total_space();
+                            let available = disk.available_space();
+                            let used_pct = if total > 0 {
+                                ((total - available) as f64 / total as f64 * 10000.0) as u32
+                            } else {
+                                0
+                            };
+                            stats_clone.disk_usage.store(used_pct, Ordering::Relaxed);
+                            check_thresholds(&stats_clone, Metric::Disk, used_pct as f64 / 10000.0);
+                        }
+                    }
+
+                    thread::sleep(tick);
                 }
             })
             .expect("Failed to spawn system monitor thread");
 
-        stats
-    });
+        Arc::new(Monitor {
+            stats,
+            exit,
+            join_handle: Mutex::new(Some(join_handle)),
+        })
+    };
+
+    *slot = Some(monitor);
+}
+
+/// Stop the background system monitor thread, if running, and block until it exits. Safe to
+/// call even if the monitor was never started. Clears the monitor slot so a later call to
+/// [`start_system_monitor`]/[`start_system_monitor_with`] actually starts a fresh thread rather
+/// than silently no-op'ing.
+pub fn stop_system_monitor() {
+    let monitor = SYSTEM_MONITOR.lock().unwrap().take();
+    if let Some(monitor) = monitor {
+        monitor.exit.store(true, Ordering::Relaxed);
+        if let Some(handle) = monitor.join_handle.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+    }
 }
 
 /// Get current CPU usage as a value between 0.0 and 1.0
 pub fn get_cpu_usage() -> f64 {
-    SYSTEM_MONITOR
-        .get()
-        .map(|stats| stats.cpu_usage.load(Ordering::Relaxed) as f64 / 10000.0)
+    monitor()
+        .map(|m| m.stats.cpu_usage.load(Ordering::Relaxed) as f64 / 10000.0)
         .unwrap_or(0.0)
 }
 
 /// Get current memory usage as a value between 0.0 and 1.0
 pub fn get_memory_usage() -> f64 {
-    SYSTEM_MONITOR
-        .get()
-        .map(|stats| stats.memory_usage.load(Ordering::Relaxed) as f64 / 10000.0)
+    monitor()
+        .map(|m| m.stats.memory_usage.load(Ordering::Relaxed) as f64 / 10000.0)
         .unwrap_or(0.0)
 }
+
+/// Get CPU usage smoothed over the last [`SMOOTHING_SLOTS`] ticks (or via EWMA, depending on the
+/// configured [`CpuSmoothing`] mode), as a value between 0.0 and 1.0. Prefer this over
+/// [`get_cpu_usage`] for UI readouts that should not flicker on transient spikes.
+pub fn get_cpu_usage_smoothed() -> f64 {
+    monitor()
+        .map(|m| m.stats.cpu_usage_smoothed.load(Ordering::Relaxed) as f64 / 10000.0)
+        .unwrap_or(0.0)
+}
+
+/// Get this process's own CPU usage as a value between 0.0 and 1.0, normalized by core count
+/// (i.e. 1.0 means this process is saturating every core; one fully busy core reads as
+/// `1.0 / core_count`).
+pub fn get_process_cpu_usage() -> f64 {
+    monitor()
+        .map(|m| m.stats.process_cpu_usage.load(Ordering::Relaxed) as f64 / 10000.0)
+        .unwrap_or(0.0)
+}
+
+/// Get this process's resident set size (RSS) in bytes.
+pub fn get_process_memory_bytes() -> u64 {
+    monitor()
+        .map(|m| m.stats.process_memory_bytes.load(Ordering::Relaxed))
+        .unwrap_or(0)
+}
+
+/// Get the current [`CpuInfo`] snapshot (core count, frequency, load average, thread count).
+/// Refreshed roughly every [`CPU_INFO_SUB_TICKS`] CPU ticks rather than every tick, since these
+/// values change slowly.
+pub fn get_cpu_info() -> CpuInfo {
+    monitor()
+        .map(|m| m.stats.cpu_info.lock().unwrap().clone())
+        .unwrap_or_default()
+}
+
+/// Get current disk usage of the main data volume as a value between 0.0 and 1.0.
+pub fn get_disk_usage() -> f64 {
+    monitor()
+        .map(|m| m.stats.disk_usage.load(Ordering::Relaxed) as f64 / 10000.0)
+        .unwrap_or(0.0)
+}
+
+/// Get a snapshot of the last [`HISTORY_CAPACITY`] CPU usage samples (0.0-1.0), oldest-to-newest.
+pub fn get_cpu_history() -> Vec<f32> {
+    monitor()
+        .map(|m| m.stats.cpu_history.lock().unwrap().snapshot())
+        .unwrap_or_default()
+}
+
+/// Get a snapshot of the last [`HISTORY_CAPACITY`] memory usage samples (0.0-1.0), oldest-to-newest.
+pub fn get_memory_history() -> Vec<f32> {
+    monitor()
+        .map(|m| m.stats.memory_history.lock().unwrap().snapshot())
+        .unwrap_or_default()
+}
+
+/// Windowed (min, max, mean) over the retained CPU history. Returns `None` if no samples yet.
+pub fn get_cpu_history_aggregates() -> Option<(f32, f32, f32)> {
+    monitor()
+        .and_then(|m| m.stats.cpu_history.lock().unwrap().aggregates())
+}
+
+/// Windowed (min, max, mean) over the retained memory history. Returns `None` if no samples yet.
+pub fn get_memory_history_aggregates() -> Option<(f32, f32, f32)> {
+    monitor()
+        .and_then(|m| m.stats.memory_history.lock().unwrap().aggregates())
+}
+
+/// Register a callback that fires when `metric` crosses `level` in `direction`. The callback
+/// only fires on the edge -- once when the metric crosses into the alerting zone, not on every
+/// subsequent tick spent past it -- and runs on the monitor's background thread, so keep it
+/// cheap (e.g. push to a channel or a lock-free queue; see [`register_threshold_channel`] for a
+/// ready-made `Receiver`-based variant). Does nothing if the monitor hasn't been started yet.
+pub fn register_threshold<F>(metric: Metric, level: f64, direction: Direction, callback: F)
+where
+    F: Fn(ThresholdEvent) + Send + Sync + 'static,
+{
+    if let Some(monitor) = monitor() {
+        monitor.stats.thresholds.lock().unwrap().push(ThresholdSubscription {
+            metric,
+            level,
+            direction,
+            armed: false,
+            callback: Box::new(callback),
+        });
+    }
+}
+
+/// Like [`register_threshold`], but delivers crossings over a channel instead of a callback, so
+/// UI code can `recv()`/`try_recv()` instead of busy-polling a getter. Returns a
+/// `std::sync::mpsc::Receiver` that yields one [`ThresholdEvent`] per crossing; it is not usable
+/// with `select!` macros, which take other channel types.
+pub fn register_threshold_channel(
+    metric: Metric,
+    level: f64,
+    direction: Direction,
+) -> Receiver<ThresholdEvent> {
+    let (tx, rx) = mpsc::channel();
+    register_threshold(metric, level, direction, move |event| {
+        let _ = tx.send(event);
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn ring_buffer_wraps_oldest_to_newest() {
+        let mut buf = RingBuffer::new();
+        for i in 0..(HISTORY_CAPACITY + 5) {
+            buf.push(i as f32);
+        }
+
+        let snapshot = buf.snapshot();
+        assert_eq!(snapshot.len(), HISTORY_CAPACITY);
+        // The first 5 samples (0..5) should have been overwritten by the wraparound.
+        assert_eq!(snapshot[0], 5.0);
+        assert_eq!(*snapshot.last().unwrap(), (HISTORY_CAPACITY + 4) as f32);
+    }
+
+    #[test]
+    fn ring_buffer_aggregates_before_full() {
+        let mut buf = RingBuffer::new();
+        assert!(buf.aggregates().is_none());
+
+        buf.push(1.0);
+        buf.push(2.0);
+        buf.push(3.0);
+        let (min, max, mean) = buf.aggregates().unwrap();
+        assert_eq!(min, 1.0);
+        assert_eq!(max, 3.0);
+        assert_eq!(mean, 2.0);
+    }
+
+    #[test]
+    fn smoothing_slot_window_averages_recent_samples() {
+        let mut state = SmoothingState::new(CpuSmoothing::SlotWindow);
+        for _ in 0..SMOOTHING_SLOTS {
+            state.push(1.0);
+        }
+
+        let smoothed = state.push(0.0);
+        assert_eq!(smoothed, (SMOOTHING_SLOTS - 1) as f32 / SMOOTHING_SLOTS as f32);
+    }
+
+    #[test]
+    fn smoothing_ewma_damps_toward_raw_value() {
+        let mut state = SmoothingState::new(CpuSmoothing::Ewma { alpha: 0.5 });
+        assert_eq!(state.push(1.0), 0.5);
+        assert_eq!(state.push(1.0), 0.75);
+    }
+
+    #[test]
+    fn threshold_fires_once_per_crossing_and_rearms_after_falling_back() {
+        let stats = SystemStats::new(CpuSmoothing::SlotWindow);
+        let fire_count = Arc::new(AtomicUsize::new(0));
+        let fire_count_clone = Arc::clone(&fire_count);
+        stats.thresholds.lock().unwrap().push(ThresholdSubscription {
+            metric: Metric::Cpu,
+            level: 0.9,
+            direction: Direction::Above,
+            armed: false,
+            callback: Box::new(move |_event| {
+                fire_count_clone.fetch_add(1, Ordering::Relaxed);
+            }),
+        });
+
+        check_thresholds(&stats, Metric::Cpu, 0.5);
+        assert_eq!(fire_count.load(Ordering::Relaxed), 0);
+
+        check_thresholds(&stats, Metric::Cpu, 0.95);
+        assert_eq!(fire_count.load(Ordering::Relaxed), 1);
+
+        // Still above the level on the next tick -- must not re-fire every tick.
+        check_thresholds(&stats, Metric::Cpu, 0.96);
+        assert_eq!(fire_count.load(Ordering::Relaxed), 1);
+
+        // Falls back below the level, disarming the subscription.
+        check_thresholds(&stats, Metric::Cpu, 0.5);
+        assert_eq!(fire_count.load(Ordering::Relaxed), 1);
+
+        // Crosses again -- should fire a second, independent time.
+        check_thresholds(&stats, Metric::Cpu, 0.95);
+        assert_eq!(fire_count.load(Ordering::Relaxed), 2);
+    }
+}